@@ -1,13 +1,16 @@
 // Copyright 2018-2020 the Deno authors. All rights reserved. MIT license.
 
+use deno_core::error::generic_error;
 use deno_core::error::AnyError;
 use deno_core::serde_json;
+use deno_core::serde_json::json;
 use deno_core::serde_json::Value;
 use jsonc_parser::JsonValue;
 use serde::Deserialize;
 use serde::Serialize;
 use serde::Serializer;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 use std::path::Path;
 use std::path::PathBuf;
@@ -45,17 +48,22 @@ impl fmt::Display for IgnoredCompilerOptions {
 /// A static slice of all the compiler options that should be ignored that
 /// either have no effect on the compilation or would cause the emit to not work
 /// in Deno.
-const IGNORED_COMPILER_OPTIONS: [&str; 61] = [
+const IGNORED_COMPILER_OPTIONS: [&str; 79] = [
+  "allowJs",
   "allowSyntheticDefaultImports",
   "allowUmdGlobalAccess",
+  "allowUnreachableCode",
+  "allowUnusedLabels",
   "assumeChangesOnlyAffectDirectDependencies",
   "baseUrl",
   "build",
+  "charset",
   "composite",
   "declaration",
   "declarationDir",
   "declarationMap",
   "diagnostics",
+  "disableSizeLimit",
   "downlevelIteration",
   "emitBOM",
   "emitDeclarationOnly",
@@ -69,8 +77,12 @@ const IGNORED_COMPILER_OPTIONS: [&str; 61] = [
   "inlineSourceMap",
   "inlineSources",
   "init",
+  "isolatedModules",
+  "keyofStringsOnly",
+  "lib",
   "listEmittedFiles",
   "listFiles",
+  "locale",
   "mapRoot",
   "maxNodeModuleJsDepth",
   "module",
@@ -79,17 +91,26 @@ const IGNORED_COMPILER_OPTIONS: [&str; 61] = [
   "noEmit",
   "noEmitHelpers",
   "noEmitOnError",
+  "noFallthroughCasesInSwitch",
+  "noImplicitOverride",
+  "noImplicitReturns",
+  "noImplicitUseStrict",
   "noLib",
+  "noPropertyAccessFromIndexSignature",
   "noResolve",
+  "noStrictGenericChecks",
+  "noUncheckedIndexedAccess",
   "out",
   "outDir",
   "outFile",
   "paths",
+  "plugins",
   "preserveConstEnums",
   "preserveSymlinks",
   "preserveWatchOutput",
   "pretty",
   "reactNamespace",
+  "removeComments",
   "resolveJsonModule",
   "rootDir",
   "rootDirs",
@@ -109,6 +130,151 @@ const IGNORED_COMPILER_OPTIONS: [&str; 61] = [
   "watch",
 ];
 
+/// The expected value type of a compiler option, used to type-check a
+/// recognized option's value before it reaches the TypeScript host.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompilerOptionType {
+  Boolean,
+  String,
+}
+
+impl fmt::Display for CompilerOptionType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CompilerOptionType::Boolean => write!(f, "boolean"),
+      CompilerOptionType::String => write!(f, "string"),
+    }
+  }
+}
+
+impl CompilerOptionType {
+  fn matches(self, value: &Value) -> bool {
+    match self {
+      CompilerOptionType::Boolean => value.is_boolean(),
+      CompilerOptionType::String => value.is_string(),
+    }
+  }
+}
+
+/// A static slice of the compiler options this codebase actually respects,
+/// along with the value type we expect the user to provide. Used both to
+/// type-check recognized options and, combined with `IGNORED_COMPILER_OPTIONS`,
+/// as the dictionary for suggesting a fix for a misspelled key.
+const KNOWN_COMPILER_OPTIONS: &[(&str, CompilerOptionType)] = &[
+  ("alwaysStrict", CompilerOptionType::Boolean),
+  ("checkJs", CompilerOptionType::Boolean),
+  ("emitDecoratorMetadata", CompilerOptionType::Boolean),
+  ("experimentalDecorators", CompilerOptionType::Boolean),
+  ("jsx", CompilerOptionType::String),
+  ("jsxFactory", CompilerOptionType::String),
+  ("jsxFragmentFactory", CompilerOptionType::String),
+  ("noImplicitAny", CompilerOptionType::Boolean),
+  ("noImplicitThis", CompilerOptionType::Boolean),
+  ("noUnusedLocals", CompilerOptionType::Boolean),
+  ("noUnusedParameters", CompilerOptionType::Boolean),
+  ("strict", CompilerOptionType::Boolean),
+  ("strictBindCallApply", CompilerOptionType::Boolean),
+  ("strictFunctionTypes", CompilerOptionType::Boolean),
+  ("strictNullChecks", CompilerOptionType::Boolean),
+  ("strictPropertyInitialization", CompilerOptionType::Boolean),
+  ("suppressExcessPropertyErrors", CompilerOptionType::Boolean),
+  ("suppressImplicitAnyIndexErrors", CompilerOptionType::Boolean),
+];
+
+/// The maximum Levenshtein edit distance at which an unknown key is still
+/// considered a plausible typo of a known one.
+const SUGGESTION_THRESHOLD: usize = 2;
+
+/// A diagnostic raised while validating a single compiler option, so the
+/// caller can print an actionable error instead of failing deep inside the
+/// TypeScript host.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompilerOptionDiagnostic {
+  /// A key that is neither a known compiler option nor in the ignore list.
+  Unknown {
+    key: String,
+    maybe_suggestion: Option<String>,
+    path: PathBuf,
+  },
+  /// A recognized key whose value didn't match the expected type.
+  InvalidType {
+    key: String,
+    expected: CompilerOptionType,
+    path: PathBuf,
+  },
+}
+
+impl fmt::Display for CompilerOptionDiagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CompilerOptionDiagnostic::Unknown {
+        key,
+        maybe_suggestion,
+        path,
+      } => {
+        write!(
+          f,
+          "Unknown compiler option \"{}\" in \"{}\".",
+          key,
+          path.to_string_lossy()
+        )?;
+        if let Some(suggestion) = maybe_suggestion {
+          write!(f, " Did you mean \"{}\"?", suggestion)?;
+        }
+        Ok(())
+      }
+      CompilerOptionDiagnostic::InvalidType {
+        key,
+        expected,
+        path,
+      } => write!(
+        f,
+        "Compiler option \"{}\" in \"{}\" should be a {}.",
+        key,
+        path.to_string_lossy(),
+        expected
+      ),
+    }
+  }
+}
+
+/// The Levenshtein edit distance between two strings, used to suggest a
+/// fix for a misspelled compiler option.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+  let a: Vec<char> = a.chars().collect();
+  let b: Vec<char> = b.chars().collect();
+  let mut row: Vec<usize> = (0..=b.len()).collect();
+
+  for (i, a_char) in a.iter().enumerate() {
+    let mut prev = row[0];
+    row[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let cost = if a_char == b_char { 0 } else { 1 };
+      let current = std::cmp::min(
+        std::cmp::min(row[j + 1] + 1, row[j] + 1),
+        prev + cost,
+      );
+      prev = row[j + 1];
+      row[j + 1] = current;
+    }
+  }
+
+  row[b.len()]
+}
+
+/// Find the closest known or ignorable compiler option key to `key`, if any
+/// is within `SUGGESTION_THRESHOLD` edits.
+fn suggest_compiler_option(key: &str) -> Option<String> {
+  KNOWN_COMPILER_OPTIONS
+    .iter()
+    .map(|(name, _)| *name)
+    .chain(IGNORED_COMPILER_OPTIONS.iter().copied())
+    .map(|name| (name, levenshtein_distance(key, name)))
+    .filter(|(_, distance)| *distance <= SUGGESTION_THRESHOLD)
+    .min_by_key(|(_, distance)| *distance)
+    .map(|(name, _)| name.to_string())
+}
+
 /// A function that works like JavaScript's `Object.assign()`.
 pub fn json_merge(a: &mut Value, b: &Value) {
   match (a, b) {
@@ -167,37 +333,166 @@ pub fn parse_raw_config(config_text: &str) -> Result<Value, AnyError> {
 }
 
 /// Take a string of JSONC, parse it and return a serde `Value` of the text.
-/// The result also contains any options that were ignored.
+/// If the config has an `extends` field, the referenced config is resolved
+/// relative to `path`'s directory, recursively parsed and deep-merged in as
+/// the base, with `path`'s own options taking precedence. The result also
+/// contains any options that were ignored, one `IgnoredCompilerOptions` per
+/// config file in the chain that ignored something, plus a flat vector of
+/// diagnostics for unknown keys (with a "did you mean" suggestion) and
+/// recognized keys whose value didn't match the expected type.
 pub fn parse_config(
   config_text: &str,
   path: &Path,
-) -> Result<(Value, Option<IgnoredCompilerOptions>), AnyError> {
+) -> Result<
+  (
+    Value,
+    Option<Vec<IgnoredCompilerOptions>>,
+    Vec<CompilerOptionDiagnostic>,
+  ),
+  AnyError,
+> {
+  let mut visited = HashSet::new();
+  parse_config_chain(config_text, path, &mut visited)
+}
+
+/// Whether an `extends` value is a relative path (`./...`, `../...`) as
+/// opposed to a bare specifier like `@tsconfig/node14/tsconfig.json`.
+fn is_relative_specifier(specifier: &str) -> bool {
+  specifier.starts_with("./")
+    || specifier.starts_with("../")
+    || specifier == "."
+    || specifier == ".."
+}
+
+/// Resolve an `extends` value relative to `base_dir`. Relative paths and
+/// absolute paths are joined onto `base_dir` directly. A bare specifier
+/// (e.g. `@tsconfig/node14/tsconfig.json` or `some-base`) is resolved like a
+/// Node package import: walk up from `base_dir` looking for a
+/// `node_modules/<specifier>`, trying the specifier as a `.json` file
+/// directly and, failing that, as a directory containing `tsconfig.json`.
+fn resolve_extends_path(
+  base_dir: &Path,
+  specifier: &str,
+) -> Result<PathBuf, AnyError> {
+  if is_relative_specifier(specifier) || Path::new(specifier).is_absolute() {
+    return Ok(base_dir.join(specifier));
+  }
+
+  let mut dir = Some(base_dir.to_path_buf());
+  while let Some(current) = dir {
+    let package_path = current.join("node_modules").join(specifier);
+    let as_file = if package_path.extension().is_some() {
+      package_path.clone()
+    } else {
+      package_path.with_extension("json")
+    };
+    if as_file.is_file() {
+      return Ok(as_file);
+    }
+    let as_package_tsconfig = package_path.join("tsconfig.json");
+    if as_package_tsconfig.is_file() {
+      return Ok(as_package_tsconfig);
+    }
+    dir = current.parent().map(|parent| parent.to_path_buf());
+  }
+
+  Err(generic_error(format!(
+    "Could not resolve \"{}\" from \"{}\": not a relative path and not found under any node_modules.",
+    specifier,
+    base_dir.to_string_lossy()
+  )))
+}
+
+fn parse_config_chain(
+  config_text: &str,
+  path: &Path,
+  visited: &mut HashSet<PathBuf>,
+) -> Result<
+  (
+    Value,
+    Option<Vec<IgnoredCompilerOptions>>,
+    Vec<CompilerOptionDiagnostic>,
+  ),
+  AnyError,
+> {
   assert!(!config_text.is_empty());
-  let jsonc = jsonc_parser::parse_to_value(config_text)?.unwrap();
+  let jsonc = jsonc_parser::parse_to_value(config_text)?.ok_or_else(|| {
+    generic_error(format!(
+      "Could not parse the config file \"{}\": no JSON value found.",
+      path.to_string_lossy()
+    ))
+  })?;
   let config: TSConfigJson = serde_json::from_value(jsonc_to_serde(jsonc))?;
+
+  let (mut value, mut ignored_options, mut diagnostics) = if let Some(extends) = &config.extends {
+    let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if !visited.insert(canonical_path) {
+      return Err(generic_error(format!(
+        "Circular extended tsconfig detected: \"{}\" extends \"{}\".",
+        path.to_string_lossy(),
+        extends
+      )));
+    }
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let extends_path = resolve_extends_path(base_dir, extends)?;
+    let extends_text = std::fs::read_to_string(&extends_path).map_err(|_| {
+      std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!(
+          "Could not find the referenced config file: {}",
+          extends_path.to_string_lossy()
+        ),
+      )
+    })?;
+    parse_config_chain(&extends_text, &extends_path, visited)?
+  } else {
+    (json!({}), None, Vec::new())
+  };
+
   let mut compiler_options: HashMap<String, Value> = HashMap::new();
   let mut items: Vec<String> = Vec::new();
 
   if let Some(in_compiler_options) = config.compiler_options {
-    for (key, value) in in_compiler_options.iter() {
+    for (key, option_value) in in_compiler_options.iter() {
       if IGNORED_COMPILER_OPTIONS.contains(&key.as_str()) {
         items.push(key.to_owned());
-      } else {
-        compiler_options.insert(key.to_owned(), value.to_owned());
+        continue;
+      }
+      match KNOWN_COMPILER_OPTIONS.iter().find(|(name, _)| name == key) {
+        Some((_, option_type)) if !option_type.matches(option_value) => {
+          diagnostics.push(CompilerOptionDiagnostic::InvalidType {
+            key: key.to_owned(),
+            expected: *option_type,
+            path: path.to_path_buf(),
+          });
+          continue;
+        }
+        None => {
+          diagnostics.push(CompilerOptionDiagnostic::Unknown {
+            key: key.to_owned(),
+            maybe_suggestion: suggest_compiler_option(key),
+            path: path.to_path_buf(),
+          });
+          continue;
+        }
+        _ => {}
       }
+      compiler_options.insert(key.to_owned(), option_value.to_owned());
     }
   }
   let options_value = serde_json::to_value(compiler_options)?;
-  let ignored_options = if !items.is_empty() {
-    Some(IgnoredCompilerOptions {
-      items,
-      path: path.to_path_buf(),
-    })
-  } else {
-    None
-  };
+  json_merge(&mut value, &options_value);
 
-  Ok((options_value, ignored_options))
+  if !items.is_empty() {
+    ignored_options.get_or_insert_with(Vec::new).push(
+      IgnoredCompilerOptions {
+        items,
+        path: path.to_path_buf(),
+      },
+    );
+  }
+
+  Ok((value, ignored_options, diagnostics))
 }
 
 /// A structure for managing the configuration of TypeScript
@@ -213,14 +508,18 @@ impl TsConfig {
   /// Take an optional string representing a user provided TypeScript config file
   /// which was passed in via the `--config` compiler option and merge it with
   /// the configuration.  Returning the result which optionally contains any
-  /// compiler options that were ignored.
+  /// compiler options that were ignored, along with any diagnostics raised
+  /// while validating the recognized and unrecognized keys.
   ///
   /// When there are options ignored out of the file, a warning will be written
   /// to stderr regarding the options that were ignored.
   pub fn merge_user_config(
     &mut self,
     maybe_path: Option<String>,
-  ) -> Result<Option<IgnoredCompilerOptions>, AnyError> {
+  ) -> Result<
+    (Option<Vec<IgnoredCompilerOptions>>, Vec<CompilerOptionDiagnostic>),
+    AnyError,
+  > {
     if let Some(path) = maybe_path {
       let cwd = std::env::current_dir()?;
       let config_file = cwd.join(path);
@@ -234,13 +533,13 @@ impl TsConfig {
         )
       })?;
       let config_text = std::fs::read_to_string(config_path.clone())?;
-      let (value, maybe_ignored_options) =
+      let (value, maybe_ignored_options, diagnostics) =
         parse_config(&config_text, &config_path)?;
       json_merge(&mut self.0, &value);
 
-      Ok(maybe_ignored_options)
+      Ok((maybe_ignored_options, diagnostics))
     } else {
-      Ok(None)
+      Ok((None, Vec::new()))
     }
   }
 
@@ -267,7 +566,6 @@ impl Serialize for TsConfig {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use deno_core::serde_json::json;
 
   #[test]
   fn test_json_merge() {
@@ -300,7 +598,7 @@ mod tests {
       }
     }"#;
     let config_path = PathBuf::from("/deno/tsconfig.json");
-    let (options_value, ignored) =
+    let (options_value, ignored, diagnostics) =
       parse_config(config_text, &config_path).expect("error parsing");
     assert!(options_value.is_object());
     let options = options_value.as_object().unwrap();
@@ -308,11 +606,156 @@ mod tests {
     assert_eq!(options.len(), 1);
     assert_eq!(
       ignored,
-      Some(IgnoredCompilerOptions {
+      Some(vec![IgnoredCompilerOptions {
         items: vec!["build".to_string()],
         path: config_path,
-      }),
+      }]),
     );
+    assert_eq!(diagnostics, Vec::new());
+  }
+
+  #[test]
+  fn test_parse_config_unknown_option_suggestion() {
+    let config_text = r#"{
+      "compilerOptions": {
+        "strlct": true
+      }
+    }"#;
+    let config_path = PathBuf::from("/deno/tsconfig.json");
+    let (options_value, _, diagnostics) =
+      parse_config(config_text, &config_path).expect("error parsing");
+    assert_eq!(
+      diagnostics,
+      vec![CompilerOptionDiagnostic::Unknown {
+        key: "strlct".to_string(),
+        maybe_suggestion: Some("strict".to_string()),
+        path: config_path,
+      }]
+    );
+    // an unknown key must not be forwarded to the TypeScript host just
+    // because it's flagged as a likely typo.
+    assert!(!options_value.as_object().unwrap().contains_key("strlct"));
+  }
+
+  #[test]
+  fn test_parse_config_invalid_option_type() {
+    let config_text = r#"{
+      "compilerOptions": {
+        "strict": "true"
+      }
+    }"#;
+    let config_path = PathBuf::from("/deno/tsconfig.json");
+    let (options_value, _, diagnostics) =
+      parse_config(config_text, &config_path).expect("error parsing");
+    assert_eq!(
+      diagnostics,
+      vec![CompilerOptionDiagnostic::InvalidType {
+        key: "strict".to_string(),
+        expected: CompilerOptionType::Boolean,
+        path: config_path,
+      }]
+    );
+    // a value that fails type-checking must not be merged into the config
+    // that gets handed to the TypeScript host.
+    assert!(!options_value.as_object().unwrap().contains_key("strict"));
+  }
+
+  #[test]
+  fn test_levenshtein_distance() {
+    assert_eq!(levenshtein_distance("strict", "strict"), 0);
+    assert_eq!(levenshtein_distance("strlct", "strict"), 1);
+    assert_eq!(levenshtein_distance("jsx", "jsxFactory"), 7);
+  }
+
+  #[test]
+  fn test_parse_config_extends() {
+    let temp_dir = std::env::temp_dir().join("deno_test_parse_config_extends");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let base_path = temp_dir.join("tsconfig.base.json");
+    std::fs::write(
+      &base_path,
+      r#"{
+        "compilerOptions": {
+          "build": true,
+          "strict": true
+        }
+      }"#,
+    )
+    .unwrap();
+    let child_path = temp_dir.join("tsconfig.json");
+    std::fs::write(
+      &child_path,
+      r#"{
+        "extends": "./tsconfig.base.json",
+        "compilerOptions": {
+          "strict": false,
+          "jsx": "react"
+        }
+      }"#,
+    )
+    .unwrap();
+
+    let config_text = std::fs::read_to_string(&child_path).unwrap();
+    let (options_value, ignored, diagnostics) =
+      parse_config(&config_text, &child_path).expect("error parsing");
+    let options = options_value.as_object().unwrap();
+    // the child's `strict: false` should win over the base's `strict: true`.
+    assert_eq!(options.get("strict"), Some(&Value::Bool(false)));
+    assert_eq!(options.get("jsx"), Some(&json!("react")));
+    assert_eq!(
+      ignored,
+      Some(vec![IgnoredCompilerOptions {
+        items: vec!["build".to_string()],
+        path: base_path,
+      }]),
+    );
+    assert_eq!(diagnostics, Vec::new());
+
+    std::fs::remove_dir_all(&temp_dir).unwrap();
+  }
+
+  #[test]
+  fn test_parse_config_extends_bare_specifier() {
+    let temp_dir =
+      std::env::temp_dir().join("deno_test_parse_config_bare_specifier");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let package_dir = temp_dir.join("node_modules").join("@tsconfig/node14");
+    std::fs::create_dir_all(&package_dir).unwrap();
+    std::fs::write(
+      package_dir.join("tsconfig.json"),
+      r#"{ "compilerOptions": { "strict": true } }"#,
+    )
+    .unwrap();
+    let child_path = temp_dir.join("tsconfig.json");
+    std::fs::write(
+      &child_path,
+      r#"{ "extends": "@tsconfig/node14/tsconfig.json" }"#,
+    )
+    .unwrap();
+
+    let config_text = std::fs::read_to_string(&child_path).unwrap();
+    let (options_value, _, _) =
+      parse_config(&config_text, &child_path).expect("error parsing");
+    let options = options_value.as_object().unwrap();
+    assert_eq!(options.get("strict"), Some(&Value::Bool(true)));
+
+    std::fs::remove_dir_all(&temp_dir).unwrap();
+  }
+
+  #[test]
+  fn test_parse_config_extends_circular() {
+    let temp_dir = std::env::temp_dir().join("deno_test_parse_config_circular");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let a_path = temp_dir.join("tsconfig.a.json");
+    let b_path = temp_dir.join("tsconfig.b.json");
+    std::fs::write(&a_path, r#"{ "extends": "./tsconfig.b.json" }"#).unwrap();
+    std::fs::write(&b_path, r#"{ "extends": "./tsconfig.a.json" }"#).unwrap();
+
+    let config_text = std::fs::read_to_string(&a_path).unwrap();
+    let err = parse_config(&config_text, &a_path).unwrap_err();
+    assert!(err.to_string().contains("Circular extended tsconfig"));
+
+    std::fs::remove_dir_all(&temp_dir).unwrap();
   }
 
   #[test]